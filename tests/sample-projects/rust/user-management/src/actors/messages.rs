@@ -0,0 +1,75 @@
+use actix::prelude::*;
+use uuid::Uuid;
+use crate::models::{User, UserRole};
+use crate::utils::errors::UserError;
+
+/// The three ways a caller may look a user up; mirrors the `GET`/`DELETE`
+/// lookup rules in `handle_request`.
+pub enum UserQuery {
+    Id(Uuid),
+    Username(String),
+    Email(String),
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<User, UserError>")]
+pub struct CreateUser {
+    pub username: String,
+    pub email: String,
+    pub first_name: String,
+    pub last_name: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<User, UserError>")]
+pub struct GetUser {
+    pub query: UserQuery,
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<(User, bool), UserError>")]
+pub struct UpsertUser {
+    pub username: String,
+    pub email: String,
+    pub first_name: String,
+    pub last_name: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<User, UserError>")]
+pub struct UpdateUser {
+    pub user_id: Uuid,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<(), UserError>")]
+pub struct DeleteUser {
+    pub query: UserQuery,
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<User, UserError>")]
+pub struct SetPassword {
+    pub query: UserQuery,
+    pub password: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "Vec<User>")]
+pub struct ListUsers;
+
+#[derive(Message)]
+#[rtype(result = "Result<User, UserError>")]
+pub struct SetRole {
+    pub user_id: Uuid,
+    pub role: UserRole,
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<User, UserError>")]
+pub struct Authenticate {
+    pub username_or_email: String,
+    pub password: String,
+}
@@ -0,0 +1,5 @@
+pub mod messages;
+pub mod user_manager_actor;
+
+pub use messages::*;
+pub use user_manager_actor::UserManagerActor;
@@ -0,0 +1,104 @@
+use actix::prelude::*;
+use crate::models::User;
+use crate::services::UserManager;
+use crate::utils::errors::UserError;
+use super::messages::{Authenticate, CreateUser, DeleteUser, GetUser, ListUsers, SetPassword, SetRole, UpdateUser, UpsertUser, UserQuery};
+
+/// Owns the single `UserManager` instance. All connections share this
+/// actor's `Addr`, so mutations are serialized through its mailbox instead
+/// of racing across per-connection clones.
+pub struct UserManagerActor {
+    manager: UserManager,
+}
+
+impl UserManagerActor {
+    pub fn new(manager: UserManager) -> Self {
+        Self { manager }
+    }
+
+    fn resolve(&self, query: &UserQuery) -> Result<User, UserError> {
+        match query {
+            UserQuery::Id(id) => self.manager.get_user_by_id(*id),
+            UserQuery::Username(username) => self.manager.get_user_by_username(username),
+            UserQuery::Email(email) => self.manager.get_user_by_email(email),
+        }
+    }
+}
+
+impl Actor for UserManagerActor {
+    type Context = Context<Self>;
+}
+
+impl Handler<CreateUser> for UserManagerActor {
+    type Result = Result<User, UserError>;
+
+    fn handle(&mut self, msg: CreateUser, _ctx: &mut Self::Context) -> Self::Result {
+        self.manager.create_user(msg.username, msg.email, msg.first_name, msg.last_name)
+    }
+}
+
+impl Handler<GetUser> for UserManagerActor {
+    type Result = Result<User, UserError>;
+
+    fn handle(&mut self, msg: GetUser, _ctx: &mut Self::Context) -> Self::Result {
+        self.resolve(&msg.query)
+    }
+}
+
+impl Handler<UpsertUser> for UserManagerActor {
+    type Result = Result<(User, bool), UserError>;
+
+    fn handle(&mut self, msg: UpsertUser, _ctx: &mut Self::Context) -> Self::Result {
+        self.manager.upsert_user(msg.username, msg.email, msg.first_name, msg.last_name)
+    }
+}
+
+impl Handler<UpdateUser> for UserManagerActor {
+    type Result = Result<User, UserError>;
+
+    fn handle(&mut self, msg: UpdateUser, _ctx: &mut Self::Context) -> Self::Result {
+        self.manager.update_user(msg.user_id, msg.first_name, msg.last_name)
+    }
+}
+
+impl Handler<DeleteUser> for UserManagerActor {
+    type Result = Result<(), UserError>;
+
+    fn handle(&mut self, msg: DeleteUser, _ctx: &mut Self::Context) -> Self::Result {
+        let user = self.resolve(&msg.query)?;
+        self.manager.delete_user(user.id)
+    }
+}
+
+impl Handler<SetPassword> for UserManagerActor {
+    type Result = Result<User, UserError>;
+
+    fn handle(&mut self, msg: SetPassword, _ctx: &mut Self::Context) -> Self::Result {
+        let user = self.resolve(&msg.query)?;
+        self.manager.set_password(user.id, &msg.password)
+    }
+}
+
+impl Handler<ListUsers> for UserManagerActor {
+    type Result = Vec<User>;
+
+    fn handle(&mut self, _msg: ListUsers, _ctx: &mut Self::Context) -> Self::Result {
+        self.manager.list_users()
+    }
+}
+
+impl Handler<SetRole> for UserManagerActor {
+    type Result = Result<User, UserError>;
+
+    fn handle(&mut self, msg: SetRole, _ctx: &mut Self::Context) -> Self::Result {
+        self.manager.set_user_role(msg.user_id, msg.role)
+    }
+}
+
+impl Handler<Authenticate> for UserManagerActor {
+    type Result = Result<User, UserError>;
+
+    fn handle(&mut self, msg: Authenticate, _ctx: &mut Self::Context) -> Self::Result {
+        self.manager.authenticate(&msg.username_or_email, &msg.password)
+    }
+}
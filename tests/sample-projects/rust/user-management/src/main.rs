@@ -0,0 +1,249 @@
+use actix::Actor;
+use tokio::net::TcpListener;
+use uuid::Uuid;
+
+mod actors;
+mod models;
+mod repositories;
+mod schema;
+mod services;
+mod utils;
+
+use actors::{Authenticate, CreateUser, DeleteUser, GetUser, ListUsers, SetPassword, UpsertUser, UserManagerActor, UserQuery};
+use actix::Addr;
+use models::{User, UserRole};
+use repositories::DieselUserRepository;
+use services::UserManager;
+use utils::{bad_request, err_response, ok_response};
+
+#[actix::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("Starting User Management Server...");
+
+    let mut user_manager = match std::env::var("DATABASE_URL") {
+        Ok(database_url) => {
+            println!("Using Postgres-backed storage");
+            UserManager::with_repository(Box::new(DieselUserRepository::new(&database_url)?))
+        }
+        Err(_) => {
+            println!("DATABASE_URL not set, using in-memory storage");
+            UserManager::new()
+        }
+    };
+
+    // Seed some sample users. `upsert_user` is used instead of `create_user`
+    // so restarting against a persistent (Diesel) backend that already has
+    // these rows doesn't fail on `DuplicateUser` before the listener binds.
+    let (admin_user, _) = user_manager.upsert_user(
+        "admin".to_string(),
+        "admin@example.com".to_string(),
+        "System".to_string(),
+        "Administrator".to_string(),
+    )?;
+    user_manager.set_user_role(admin_user.id, UserRole::Admin)?;
+
+    user_manager.upsert_user(
+        "john_doe".to_string(),
+        "john@example.com".to_string(),
+        "John".to_string(),
+        "Doe".to_string(),
+    )?;
+
+    let manager_addr = UserManagerActor::new(user_manager).start();
+
+    // Start TCP server
+    let listener = TcpListener::bind("127.0.0.1:8080").await?;
+    println!("Server listening on 127.0.0.1:8080");
+
+    loop {
+        let (mut socket, _addr) = listener.accept().await?;
+        let manager_addr = manager_addr.clone();
+
+        tokio::spawn(async move {
+            let mut buffer = [0; 1024];
+            match socket.read(&mut buffer).await {
+                Ok(n) => {
+                    if n == 0 {
+                        return;
+                    }
+
+                    let request = String::from_utf8_lossy(&buffer[..n]);
+                    let response = handle_request(&request, &manager_addr).await;
+
+                    if let Err(e) = socket.write_all(response.as_bytes()).await {
+                        eprintln!("Failed to send response: {}", e);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Failed to read from socket: {}", e);
+                }
+            }
+        });
+    }
+}
+
+async fn handle_request(request: &str, user_manager: &Addr<UserManagerActor>) -> String {
+    let parts: Vec<&str> = request.trim().split_whitespace().collect();
+
+    if parts.is_empty() {
+        return bad_request("Empty request");
+    }
+
+    match parts[0] {
+        "CREATE" => {
+            if parts.len() < 5 {
+                return bad_request("Usage: CREATE username email first_name last_name");
+            }
+
+            let msg = CreateUser {
+                username: parts[1].to_string(),
+                email: parts[2].to_string(),
+                first_name: parts[3].to_string(),
+                last_name: parts[4].to_string(),
+            };
+
+            match user_manager.send(msg).await {
+                Ok(Ok(user)) => ok_response(format!("User created with ID: {}", user.id)),
+                Ok(Err(e)) => err_response(&e),
+                Err(e) => format!("500 MAILBOX_ERROR: {}", e),
+            }
+        }
+        "GET" => {
+            if parts.len() < 2 {
+                return bad_request("Usage: GET <username|email|id>");
+            }
+
+            let query = parse_query(parts[1]);
+            match user_manager.send(GetUser { query }).await {
+                Ok(Ok(user)) => ok_response(format_user_info(&user)),
+                Ok(Err(e)) => err_response(&e),
+                Err(e) => format!("500 MAILBOX_ERROR: {}", e),
+            }
+        }
+        "UPSERT" => {
+            if parts.len() < 5 {
+                return bad_request("Usage: UPSERT username email first_name last_name");
+            }
+
+            let msg = UpsertUser {
+                username: parts[1].to_string(),
+                email: parts[2].to_string(),
+                first_name: parts[3].to_string(),
+                last_name: parts[4].to_string(),
+            };
+
+            match user_manager.send(msg).await {
+                Ok(Ok((user, created))) => {
+                    let verb = if created { "created" } else { "updated" };
+                    ok_response(format!("User {} with ID: {}", verb, user.id))
+                }
+                Ok(Err(e)) => err_response(&e),
+                Err(e) => format!("500 MAILBOX_ERROR: {}", e),
+            }
+        }
+        "LIST" => {
+            match user_manager.send(ListUsers).await {
+                Ok(users) if users.is_empty() => ok_response("No users found"),
+                Ok(users) => {
+                    let user_list: Vec<String> = users.iter()
+                        .map(|u| format!("{} ({})", u.username, u.email))
+                        .collect();
+
+                    ok_response(format!("Users:\n{}", user_list.join("\n")))
+                }
+                Err(e) => format!("500 MAILBOX_ERROR: {}", e),
+            }
+        }
+        "DELETE" => {
+            if parts.len() < 2 {
+                return bad_request("Usage: DELETE <username|email|id>");
+            }
+
+            let query = parse_query(parts[1]);
+            match user_manager.send(DeleteUser { query }).await {
+                Ok(Ok(())) => ok_response("User deleted"),
+                Ok(Err(e)) => err_response(&e),
+                Err(e) => format!("500 MAILBOX_ERROR: {}", e),
+            }
+        }
+        "SET_PASSWORD" => {
+            if parts.len() < 3 {
+                return bad_request("Usage: SET_PASSWORD <username|email|id> password");
+            }
+
+            let query = parse_query(parts[1]);
+            let msg = SetPassword {
+                query,
+                password: parts[2].to_string(),
+            };
+
+            match user_manager.send(msg).await {
+                Ok(Ok(user)) => ok_response(format!("Password updated for {}", user.username)),
+                Ok(Err(e)) => err_response(&e),
+                Err(e) => format!("500 MAILBOX_ERROR: {}", e),
+            }
+        }
+        "AUTH" => {
+            if parts.len() < 3 {
+                return bad_request("Usage: AUTH <username|email> password");
+            }
+
+            let msg = Authenticate {
+                username_or_email: parts[1].to_string(),
+                password: parts[2].to_string(),
+            };
+
+            match user_manager.send(msg).await {
+                Ok(Ok(user)) => ok_response(format!("Authenticated as {}", user.username)),
+                Ok(Err(e)) => err_response(&e),
+                Err(e) => format!("500 MAILBOX_ERROR: {}", e),
+            }
+        }
+        "HELP" => {
+            "Available commands:\n\
+             CREATE username email first_name last_name - Create a new user\n\
+             GET <username|email|id> - Get user information\n\
+             LIST - List all users\n\
+             DELETE <username|email|id> - Delete a user\n\
+             UPSERT username email first_name last_name - Create or update a user\n\
+             SET_PASSWORD <username|email|id> password - Set a user's password\n\
+             AUTH <username|email> password - Authenticate a user\n\
+             HELP - Show this help message".to_string()
+        }
+        _ => {
+            bad_request("Unknown command. Type HELP for available commands.")
+        }
+    }
+}
+
+fn parse_query(token: &str) -> UserQuery {
+    if let Ok(uuid) = Uuid::parse_str(token) {
+        UserQuery::Id(uuid)
+    } else if token.contains('@') {
+        UserQuery::Email(token.to_string())
+    } else {
+        UserQuery::Username(token.to_string())
+    }
+}
+
+fn format_user_info(user: &User) -> String {
+    format!(
+        "User ID: {}\n\
+         Username: {}\n\
+         Email: {}\n\
+         Name: {} {}\n\
+         Role: {:?}\n\
+         Status: {:?}\n\
+         Created: {}\n\
+         Updated: {}",
+        user.id,
+        user.username,
+        user.email,
+        user.first_name,
+        user.last_name,
+        user.role,
+        user.status,
+        user.created_at,
+        user.updated_at
+    )
+}
@@ -1,6 +1,15 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
+use pbkdf2::pbkdf2_hmac;
+use sha2::Sha256;
+use rand::RngCore;
+use subtle::ConstantTimeEq;
+
+/// Default PBKDF2 round count for newly-set passwords.
+pub const DEFAULT_PASSWORD_ITERATIONS: i32 = 100_000;
+const SALT_LEN: usize = 16;
+const HASH_LEN: usize = 32;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
@@ -13,6 +22,14 @@ pub struct User {
     pub status: UserStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing, default)]
+    pub password_hash: Vec<u8>,
+    #[serde(skip_serializing, default)]
+    pub salt: Vec<u8>,
+    #[serde(skip_serializing, default = "User::default_password_iterations")]
+    pub password_iterations: i32,
+    #[serde(skip_serializing, default)]
+    pub security_stamp: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,6 +56,10 @@ pub enum UserStatus {
 }
 
 impl User {
+    fn default_password_iterations() -> i32 {
+        DEFAULT_PASSWORD_ITERATIONS
+    }
+
     pub fn new(username: String, email: String, first_name: String, last_name: String) -> Self {
         let now = Utc::now();
         Self {
@@ -51,9 +72,55 @@ impl User {
             status: UserStatus::Pending,
             created_at: now,
             updated_at: now,
+            password_hash: Vec::new(),
+            salt: Vec::new(),
+            password_iterations: DEFAULT_PASSWORD_ITERATIONS,
+            security_stamp: Uuid::new_v4().to_string(),
         }
     }
 
+    /// Hashes `plain` with a fresh random salt using PBKDF2-HMAC-SHA256 and
+    /// stores the derived key. Rotates `security_stamp` so any session bound
+    /// to the previous stamp is invalidated.
+    pub fn set_password(&mut self, plain: &str) {
+        let mut salt = vec![0u8; SALT_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+
+        let mut hash = [0u8; HASH_LEN];
+        pbkdf2_hmac::<Sha256>(plain.as_bytes(), &salt, self.password_iterations as u32, &mut hash);
+
+        self.salt = salt;
+        self.password_hash = hash.to_vec();
+        self.security_stamp = Uuid::new_v4().to_string();
+        self.updated_at = Utc::now();
+    }
+
+    /// Re-derives the key for `plain` with the stored salt/iterations and
+    /// compares it against `password_hash` in constant time.
+    pub fn verify_password(&self, plain: &str) -> bool {
+        if self.salt.is_empty() || self.password_hash.is_empty() {
+            return false;
+        }
+
+        let mut hash = vec![0u8; self.password_hash.len()];
+        pbkdf2_hmac::<Sha256>(plain.as_bytes(), &self.salt, self.password_iterations as u32, &mut hash);
+
+        hash.ct_eq(&self.password_hash).into()
+    }
+
+    /// Runs a PBKDF2 derivation against a fixed decoy salt without comparing
+    /// the result to anything. Callers that fail a username/email lookup
+    /// should invoke this before returning `InvalidCredentials`, so a
+    /// nonexistent account takes roughly as long to reject as a wrong
+    /// password does, closing a timing side-channel that would otherwise let
+    /// an attacker enumerate valid accounts.
+    pub fn decoy_verify_password(plain: &str) {
+        const DECOY_SALT: &[u8] = b"0000000000000000";
+        let mut hash = [0u8; HASH_LEN];
+        pbkdf2_hmac::<Sha256>(plain.as_bytes(), DECOY_SALT, DEFAULT_PASSWORD_ITERATIONS as u32, &mut hash);
+        std::hint::black_box(hash);
+    }
+
     pub fn activate(&mut self) {
         self.status = UserStatus::Active;
         self.updated_at = Utc::now();
@@ -0,0 +1,318 @@
+use diesel::prelude::*;
+use diesel::r2d2::{self, ConnectionManager, Pool};
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use uuid::Uuid;
+
+use crate::models::{User, UserRole, UserStatus};
+use crate::schema::users;
+use crate::schema::users::dsl;
+use crate::utils::errors::UserError;
+use crate::utils::validators::validate_user_fields;
+use super::user_repository::UserRepository;
+
+type PgPool = Pool<ConnectionManager<PgConnection>>;
+
+/// Row shape used to move data in and out of the `users` table. `role` and
+/// `status` are stored as their `Debug` text form and parsed back on load,
+/// mirroring how the in-memory backend keeps the enums themselves.
+#[derive(Queryable, Insertable, AsChangeset)]
+#[diesel(table_name = users)]
+struct UserRow {
+    id: Uuid,
+    username: String,
+    email: String,
+    first_name: String,
+    last_name: String,
+    role: String,
+    status: String,
+    password_hash: Vec<u8>,
+    salt: Vec<u8>,
+    password_iterations: i32,
+    security_stamp: String,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub struct DieselUserRepository {
+    pool: PgPool,
+}
+
+impl DieselUserRepository {
+    pub fn new(database_url: &str) -> Result<Self, UserError> {
+        let manager = ConnectionManager::<PgConnection>::new(database_url);
+        let pool = r2d2::Pool::builder()
+            .build(manager)
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        Ok(Self { pool })
+    }
+
+    fn connection(&self) -> Result<r2d2::PooledConnection<ConnectionManager<PgConnection>>, UserError> {
+        self.pool.get().map_err(|e| UserError::DatabaseError(e.to_string()))
+    }
+}
+
+fn row_to_user(row: UserRow) -> User {
+    User {
+        id: row.id,
+        username: row.username,
+        email: row.email,
+        first_name: row.first_name,
+        last_name: row.last_name,
+        role: parse_role(&row.role),
+        status: parse_status(&row.status),
+        created_at: row.created_at,
+        updated_at: row.updated_at,
+        password_hash: row.password_hash,
+        salt: row.salt,
+        password_iterations: row.password_iterations,
+        security_stamp: row.security_stamp,
+    }
+}
+
+fn user_to_row(user: &User) -> UserRow {
+    UserRow {
+        id: user.id,
+        username: user.username.clone(),
+        email: user.email.clone(),
+        first_name: user.first_name.clone(),
+        last_name: user.last_name.clone(),
+        role: format!("{:?}", user.role),
+        status: format!("{:?}", user.status),
+        password_hash: user.password_hash.clone(),
+        salt: user.salt.clone(),
+        password_iterations: user.password_iterations,
+        security_stamp: user.security_stamp.clone(),
+        created_at: user.created_at,
+        updated_at: user.updated_at,
+    }
+}
+
+fn parse_role(value: &str) -> UserRole {
+    match value {
+        "Admin" => UserRole::Admin,
+        "Manager" => UserRole::Manager,
+        "Guest" => UserRole::Guest,
+        _ => UserRole::User,
+    }
+}
+
+fn parse_status(value: &str) -> UserStatus {
+    match value {
+        "Active" => UserStatus::Active,
+        "Suspended" => UserStatus::Suspended,
+        "Pending" => UserStatus::Pending,
+        _ => UserStatus::Inactive,
+    }
+}
+
+fn map_insert_error(e: DieselError, username: &str, email: &str) -> UserError {
+    match e {
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info) => {
+            let message = info.message();
+            if message.contains("username") {
+                UserError::DuplicateUser(format!("Username '{}' already exists", username))
+            } else if message.contains("email") {
+                UserError::DuplicateUser(format!("Email '{}' already exists", email))
+            } else {
+                UserError::DuplicateUser(message.to_string())
+            }
+        }
+        other => UserError::DatabaseError(other.to_string()),
+    }
+}
+
+impl UserRepository for DieselUserRepository {
+    fn create_user(&mut self, username: String, email: String,
+                   first_name: String, last_name: String) -> Result<User, UserError> {
+        validate_user_fields(&username, &email)?;
+
+        let mut user = User::new(username.clone(), email.clone(), first_name, last_name);
+        user.activate();
+
+        let mut conn = self.connection()?;
+        diesel::insert_into(users::table)
+            .values(user_to_row(&user))
+            .execute(&mut conn)
+            .map_err(|e| map_insert_error(e, &username, &email))?;
+
+        Ok(user)
+    }
+
+    fn upsert_user(&mut self, username: String, email: String,
+                   first_name: String, last_name: String) -> Result<(User, bool), UserError> {
+        validate_user_fields(&username, &email)?;
+
+        let mut user = User::new(username, email, first_name, last_name);
+        user.activate();
+        let row = user_to_row(&user);
+
+        let mut conn = self.connection()?;
+        let inserted_id: Uuid = diesel::insert_into(users::table)
+            .values(&row)
+            .on_conflict(dsl::username)
+            .do_update()
+            .set((
+                dsl::first_name.eq(&row.first_name),
+                dsl::last_name.eq(&row.last_name),
+                dsl::updated_at.eq(row.updated_at),
+            ))
+            .returning(dsl::id)
+            .get_result(&mut conn)
+            .map_err(|e| map_insert_error(e, &user.username, &user.email))?;
+
+        let created = inserted_id == user.id;
+        let stored = self.get_user_by_id(inserted_id)?;
+        Ok((stored, created))
+    }
+
+    fn get_user_by_id(&self, user_id: Uuid) -> Result<User, UserError> {
+        let mut conn = self.connection()?;
+        dsl::users.find(user_id)
+            .first::<UserRow>(&mut conn)
+            .map(row_to_user)
+            .map_err(|_| UserError::UserNotFound(format!("User ID '{}' not found", user_id)))
+    }
+
+    fn get_user_by_username(&self, username: &str) -> Result<User, UserError> {
+        let mut conn = self.connection()?;
+        dsl::users.filter(dsl::username.eq(username))
+            .first::<UserRow>(&mut conn)
+            .map(row_to_user)
+            .map_err(|_| UserError::UserNotFound(format!("Username '{}' not found", username)))
+    }
+
+    fn get_user_by_email(&self, email: &str) -> Result<User, UserError> {
+        let mut conn = self.connection()?;
+        dsl::users.filter(dsl::email.eq(email))
+            .first::<UserRow>(&mut conn)
+            .map(row_to_user)
+            .map_err(|_| UserError::UserNotFound(format!("Email '{}' not found", email)))
+    }
+
+    fn update_user(&mut self, user_id: Uuid, first_name: Option<String>,
+                   last_name: Option<String>) -> Result<User, UserError> {
+        let mut user = self.get_user_by_id(user_id)?;
+
+        if let Some(fname) = first_name {
+            user.first_name = fname;
+        }
+
+        if let Some(lname) = last_name {
+            user.last_name = lname;
+        }
+
+        user.updated_at = chrono::Utc::now();
+
+        let mut conn = self.connection()?;
+        diesel::update(dsl::users.find(user_id))
+            .set(user_to_row(&user))
+            .execute(&mut conn)
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        Ok(user)
+    }
+
+    fn delete_user(&mut self, user_id: Uuid) -> Result<(), UserError> {
+        let mut conn = self.connection()?;
+        let affected = diesel::delete(dsl::users.find(user_id))
+            .execute(&mut conn)
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        if affected == 0 {
+            return Err(UserError::UserNotFound(format!("User ID '{}' not found", user_id)));
+        }
+
+        Ok(())
+    }
+
+    fn set_password(&mut self, user_id: Uuid, plain_password: &str) -> Result<User, UserError> {
+        let mut user = self.get_user_by_id(user_id)?;
+        user.set_password(plain_password);
+
+        let mut conn = self.connection()?;
+        diesel::update(dsl::users.find(user_id))
+            .set((
+                dsl::password_hash.eq(&user.password_hash),
+                dsl::salt.eq(&user.salt),
+                dsl::password_iterations.eq(user.password_iterations),
+                dsl::security_stamp.eq(&user.security_stamp),
+                dsl::updated_at.eq(user.updated_at),
+            ))
+            .execute(&mut conn)
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        Ok(user)
+    }
+
+    fn list_users(&self) -> Vec<User> {
+        let Ok(mut conn) = self.connection() else {
+            return Vec::new();
+        };
+
+        dsl::users.load::<UserRow>(&mut conn)
+            .map(|rows| rows.into_iter().map(row_to_user).collect())
+            .unwrap_or_default()
+    }
+
+    fn set_user_role(&mut self, user_id: Uuid, role: UserRole) -> Result<User, UserError> {
+        let mut user = self.get_user_by_id(user_id)?;
+        user.set_role(role);
+
+        let mut conn = self.connection()?;
+        diesel::update(dsl::users.find(user_id))
+            .set(user_to_row(&user))
+            .execute(&mut conn)
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        Ok(user)
+    }
+
+    fn activate_user(&mut self, user_id: Uuid) -> Result<User, UserError> {
+        let mut user = self.get_user_by_id(user_id)?;
+        user.activate();
+
+        let mut conn = self.connection()?;
+        diesel::update(dsl::users.find(user_id))
+            .set(dsl::status.eq(format!("{:?}", user.status)))
+            .execute(&mut conn)
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        Ok(user)
+    }
+
+    fn deactivate_user(&mut self, user_id: Uuid) -> Result<User, UserError> {
+        let mut user = self.get_user_by_id(user_id)?;
+        user.deactivate();
+
+        let mut conn = self.connection()?;
+        diesel::update(dsl::users.find(user_id))
+            .set(dsl::status.eq(format!("{:?}", user.status)))
+            .execute(&mut conn)
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        Ok(user)
+    }
+
+    fn get_active_users(&self) -> Vec<User> {
+        let Ok(mut conn) = self.connection() else {
+            return Vec::new();
+        };
+
+        dsl::users.filter(dsl::status.eq("Active"))
+            .load::<UserRow>(&mut conn)
+            .map(|rows| rows.into_iter().map(row_to_user).collect())
+            .unwrap_or_default()
+    }
+
+    fn get_users_by_role(&self, role: UserRole) -> Vec<User> {
+        let Ok(mut conn) = self.connection() else {
+            return Vec::new();
+        };
+
+        dsl::users.filter(dsl::role.eq(format!("{:?}", role)))
+            .load::<UserRow>(&mut conn)
+            .map(|rows| rows.into_iter().map(row_to_user).collect())
+            .unwrap_or_default()
+    }
+}
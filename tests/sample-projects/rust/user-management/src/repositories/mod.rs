@@ -0,0 +1,7 @@
+pub mod user_repository;
+pub mod in_memory;
+pub mod diesel_repository;
+
+pub use user_repository::UserRepository;
+pub use in_memory::InMemoryUserRepository;
+pub use diesel_repository::DieselUserRepository;
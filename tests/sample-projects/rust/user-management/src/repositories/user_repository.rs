@@ -0,0 +1,38 @@
+use uuid::Uuid;
+use crate::models::{User, UserRole};
+use crate::utils::errors::UserError;
+
+/// CRUD surface shared by every user storage backend, so the in-memory and
+/// persistent implementations are drop-in interchangeable.
+pub trait UserRepository: Send {
+    fn create_user(&mut self, username: String, email: String,
+                   first_name: String, last_name: String) -> Result<User, UserError>;
+
+    /// Inserts a new active user when neither index matches, or updates the
+    /// existing record's names when `username` matches. The `bool` is `true`
+    /// when a new row was created.
+    fn upsert_user(&mut self, username: String, email: String,
+                   first_name: String, last_name: String) -> Result<(User, bool), UserError>;
+
+    fn get_user_by_id(&self, user_id: Uuid) -> Result<User, UserError>;
+    fn get_user_by_username(&self, username: &str) -> Result<User, UserError>;
+    fn get_user_by_email(&self, email: &str) -> Result<User, UserError>;
+
+    fn update_user(&mut self, user_id: Uuid, first_name: Option<String>,
+                   last_name: Option<String>) -> Result<User, UserError>;
+
+    fn delete_user(&mut self, user_id: Uuid) -> Result<(), UserError>;
+
+    /// Hashes `plain_password` and stores it on the user, rotating their
+    /// `security_stamp` in the process.
+    fn set_password(&mut self, user_id: Uuid, plain_password: &str) -> Result<User, UserError>;
+
+    fn list_users(&self) -> Vec<User>;
+
+    fn set_user_role(&mut self, user_id: Uuid, role: UserRole) -> Result<User, UserError>;
+    fn activate_user(&mut self, user_id: Uuid) -> Result<User, UserError>;
+    fn deactivate_user(&mut self, user_id: Uuid) -> Result<User, UserError>;
+
+    fn get_active_users(&self) -> Vec<User>;
+    fn get_users_by_role(&self, role: UserRole) -> Vec<User>;
+}
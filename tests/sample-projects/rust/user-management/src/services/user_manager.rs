@@ -1,133 +1,106 @@
-use std::collections::HashMap;
 use uuid::Uuid;
-use crate::models::{User, UserRole, UserStatus};
+use crate::models::{User, UserRole};
+use crate::repositories::{InMemoryUserRepository, UserRepository};
 use crate::utils::errors::UserError;
-use crate::utils::validators::{validate_email, validate_username};
 
+/// Thin façade over a `UserRepository`. Keeping all storage behind the trait
+/// means the in-memory and Diesel-backed stores are interchangeable without
+/// touching any of the call sites below.
 pub struct UserManager {
-    users: HashMap<Uuid, User>,
-    username_index: HashMap<String, Uuid>,
-    email_index: HashMap<String, Uuid>,
+    repository: Box<dyn UserRepository>,
 }
 
 impl UserManager {
     pub fn new() -> Self {
         Self {
-            users: HashMap::new(),
-            username_index: HashMap::new(),
-            email_index: HashMap::new(),
+            repository: Box::new(InMemoryUserRepository::new()),
         }
     }
 
-    pub fn create_user(&mut self, username: String, email: String, 
-                      first_name: String, last_name: String) -> Result<User, UserError> {
-        validate_username(&username)?;
-        validate_email(&email)?;
-
-        if self.username_index.contains_key(&username) {
-            return Err(UserError::DuplicateUser(format!("Username '{}' already exists", username)));
-        }
-
-        if self.email_index.contains_key(&email) {
-            return Err(UserError::DuplicateUser(format!("Email '{}' already exists", email)));
-        }
-
-        let mut user = User::new(username, email, first_name, last_name);
-        user.activate();
-
-        let user_id = user.id;
-        let username_clone = user.username.clone();
-        let email_clone = user.email.clone();
+    pub fn with_repository(repository: Box<dyn UserRepository>) -> Self {
+        Self { repository }
+    }
 
-        self.users.insert(user_id, user.clone());
-        self.username_index.insert(username_clone, user_id);
-        self.email_index.insert(email_clone, user_id);
+    pub fn create_user(&mut self, username: String, email: String,
+                      first_name: String, last_name: String) -> Result<User, UserError> {
+        self.repository.create_user(username, email, first_name, last_name)
+    }
 
-        Ok(user)
+    pub fn upsert_user(&mut self, username: String, email: String,
+                       first_name: String, last_name: String) -> Result<(User, bool), UserError> {
+        self.repository.upsert_user(username, email, first_name, last_name)
     }
 
     pub fn get_user_by_id(&self, user_id: Uuid) -> Result<User, UserError> {
-        self.users.get(&user_id)
-            .cloned()
-            .ok_or_else(|| UserError::UserNotFound(format!("User ID '{}' not found", user_id)))
+        self.repository.get_user_by_id(user_id)
     }
 
     pub fn get_user_by_username(&self, username: &str) -> Result<User, UserError> {
-        let user_id = self.username_index.get(username)
-            .ok_or_else(|| UserError::UserNotFound(format!("Username '{}' not found", username)))?;
-        self.get_user_by_id(*user_id)
+        self.repository.get_user_by_username(username)
     }
 
     pub fn get_user_by_email(&self, email: &str) -> Result<User, UserError> {
-        let user_id = self.email_index.get(email)
-            .ok_or_else(|| UserError::UserNotFound(format!("Email '{}' not found", email)))?;
-        self.get_user_by_id(*user_id)
+        self.repository.get_user_by_email(email)
     }
 
-    pub fn update_user(&mut self, user_id: Uuid, first_name: Option<String>, 
+    pub fn update_user(&mut self, user_id: Uuid, first_name: Option<String>,
                        last_name: Option<String>) -> Result<User, UserError> {
-        let mut user = self.get_user_by_id(user_id)?;
-        
-        if let Some(fname) = first_name {
-            user.first_name = fname;
-        }
-        
-        if let Some(lname) = last_name {
-            user.last_name = lname;
-        }
-        
-        user.updated_at = chrono::Utc::now();
-        
-        self.users.insert(user_id, user.clone());
-        Ok(user)
+        self.repository.update_user(user_id, first_name, last_name)
     }
 
     pub fn delete_user(&mut self, user_id: Uuid) -> Result<(), UserError> {
-        let user = self.get_user_by_id(user_id)?;
-        
-        self.users.remove(&user_id);
-        self.username_index.remove(&user.username);
-        self.email_index.remove(&user.email);
-        
-        Ok(())
+        self.repository.delete_user(user_id)
+    }
+
+    pub fn set_password(&mut self, user_id: Uuid, plain_password: &str) -> Result<User, UserError> {
+        self.repository.set_password(user_id, plain_password)
     }
 
     pub fn list_users(&self) -> Vec<User> {
-        self.users.values().cloned().collect()
+        self.repository.list_users()
     }
 
     pub fn set_user_role(&mut self, user_id: Uuid, role: UserRole) -> Result<User, UserError> {
-        let mut user = self.get_user_by_id(user_id)?;
-        user.set_role(role);
-        self.users.insert(user_id, user.clone());
-        Ok(user)
+        self.repository.set_user_role(user_id, role)
     }
 
     pub fn activate_user(&mut self, user_id: Uuid) -> Result<User, UserError> {
-        let mut user = self.get_user_by_id(user_id)?;
-        user.activate();
-        self.users.insert(user_id, user.clone());
-        Ok(user)
+        self.repository.activate_user(user_id)
     }
 
     pub fn deactivate_user(&mut self, user_id: Uuid) -> Result<User, UserError> {
-        let mut user = self.get_user_by_id(user_id)?;
-        user.deactivate();
-        self.users.insert(user_id, user.clone());
-        Ok(user)
+        self.repository.deactivate_user(user_id)
     }
 
     pub fn get_active_users(&self) -> Vec<User> {
-        self.users.values()
-            .filter(|user| user.status == UserStatus::Active)
-            .cloned()
-            .collect()
+        self.repository.get_active_users()
     }
 
     pub fn get_users_by_role(&self, role: UserRole) -> Vec<User> {
-        self.users.values()
-            .filter(|user| user.role == role)
-            .cloned()
-            .collect()
+        self.repository.get_users_by_role(role)
     }
-}
\ No newline at end of file
+
+    pub fn authenticate(&self, username_or_email: &str, password: &str) -> Result<User, UserError> {
+        let lookup = if username_or_email.contains('@') {
+            self.get_user_by_email(username_or_email)
+        } else {
+            self.get_user_by_username(username_or_email)
+        };
+
+        let user = match lookup {
+            Ok(user) => user,
+            Err(_) => {
+                // Hash anyway so a nonexistent account takes as long to
+                // reject as a wrong password, not just an indexed lookup.
+                User::decoy_verify_password(password);
+                return Err(UserError::InvalidCredentials);
+            }
+        };
+
+        if user.verify_password(password) {
+            Ok(user)
+        } else {
+            Err(UserError::InvalidCredentials)
+        }
+    }
+}
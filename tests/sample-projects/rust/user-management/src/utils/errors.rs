@@ -1,16 +1,20 @@
 use thiserror::Error;
+use super::validators::ValidationErrors;
 
 #[derive(Debug, Error)]
 pub enum UserError {
     #[error("User not found: {0}")]
     UserNotFound(String),
-    
+
     #[error("Duplicate user: {0}")]
     DuplicateUser(String),
-    
+
     #[error("Validation error: {0}")]
-    ValidationError(String),
-    
+    ValidationError(ValidationErrors),
+
     #[error("Database error: {0}")]
     DatabaseError(String),
+
+    #[error("Invalid credentials")]
+    InvalidCredentials,
 }
\ No newline at end of file
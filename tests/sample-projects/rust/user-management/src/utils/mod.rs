@@ -1,5 +1,7 @@
 pub mod errors;
+pub mod response;
 pub mod validators;
 
 pub use errors::{UserError};
-pub use validators::{validate_email, validate_username, sanitize_string};
\ No newline at end of file
+pub use response::{bad_request, err_response, ok_response};
+pub use validators::{validate_email, validate_username, validate_user_fields, sanitize_string, ValidationError, ValidationErrors};
@@ -0,0 +1,31 @@
+use std::fmt::Display;
+use super::errors::UserError;
+
+/// Machine-readable status code prefixed on every TCP response line, so
+/// scripted clients can branch on the leading token instead of parsing the
+/// human-readable message that follows it.
+fn status_code(error: &UserError) -> &'static str {
+    match error {
+        UserError::UserNotFound(_) => "404 USER_NOT_FOUND",
+        UserError::DuplicateUser(_) => "409 DUPLICATE_USER",
+        UserError::ValidationError(_) => "422 VALIDATION_ERROR",
+        UserError::DatabaseError(_) => "500 DATABASE_ERROR",
+        UserError::InvalidCredentials => "401 INVALID_CREDENTIALS",
+    }
+}
+
+/// Formats a successful result as `200 OK: <body>`.
+pub fn ok_response(body: impl Display) -> String {
+    format!("200 OK: {}", body)
+}
+
+/// Formats a failed result as `<code> <NAME>: <message>`.
+pub fn err_response(error: &UserError) -> String {
+    format!("{}: {}", status_code(error), error)
+}
+
+/// Formats a malformed request (empty input, wrong argument count, unknown
+/// command) that never reaches a `UserError`.
+pub fn bad_request(message: impl Display) -> String {
+    format!("400 BAD_REQUEST: {}", message)
+}
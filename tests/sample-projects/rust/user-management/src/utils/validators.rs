@@ -0,0 +1,122 @@
+use std::fmt;
+use regex::Regex;
+use crate::utils::errors::UserError;
+
+const MIN_USERNAME_LEN: usize = 3;
+const MAX_USERNAME_LEN: usize = 50;
+
+/// A single field-level validation failure, carrying enough detail for a
+/// caller (or a future JSON/API layer) to tell which field failed and why,
+/// rather than parsing an English sentence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    EmailFormat,
+    UsernameTooShort { min: usize, actual: usize },
+    UsernameTooLong { max: usize, actual: usize },
+    UsernameCharset,
+}
+
+impl ValidationError {
+    pub fn field(&self) -> &'static str {
+        match self {
+            ValidationError::EmailFormat => "email",
+            ValidationError::UsernameTooShort { .. }
+            | ValidationError::UsernameTooLong { .. }
+            | ValidationError::UsernameCharset => "username",
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValidationError::EmailFormat => "EMAIL_FORMAT",
+            ValidationError::UsernameTooShort { .. } => "USERNAME_TOO_SHORT",
+            ValidationError::UsernameTooLong { .. } => "USERNAME_TOO_LONG",
+            ValidationError::UsernameCharset => "USERNAME_CHARSET",
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::EmailFormat => write!(f, "Invalid email format"),
+            ValidationError::UsernameTooShort { min, actual } => {
+                write!(f, "Username must be at least {} characters (got {})", min, actual)
+            }
+            ValidationError::UsernameTooLong { max, actual } => {
+                write!(f, "Username must be less than {} characters (got {})", max, actual)
+            }
+            ValidationError::UsernameCharset => {
+                write!(f, "Username can only contain letters, numbers, and underscores")
+            }
+        }
+    }
+}
+
+/// One or more field failures aggregated from a single validation pass, so a
+/// caller submitting a bad username *and* a bad email learns about both at
+/// once instead of one at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationErrors(pub Vec<ValidationError>);
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let joined: Vec<String> = self.0.iter().map(|e| e.to_string()).collect();
+        write!(f, "{}", joined.join("; "))
+    }
+}
+
+pub fn validate_email(email: &str) -> Result<(), ValidationError> {
+    let email_regex = Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$")
+        .expect("email regex is a fixed, valid pattern");
+
+    if !email_regex.is_match(email) {
+        return Err(ValidationError::EmailFormat);
+    }
+
+    Ok(())
+}
+
+pub fn validate_username(username: &str) -> Result<(), ValidationError> {
+    if username.len() < MIN_USERNAME_LEN {
+        return Err(ValidationError::UsernameTooShort { min: MIN_USERNAME_LEN, actual: username.len() });
+    }
+
+    if username.len() > MAX_USERNAME_LEN {
+        return Err(ValidationError::UsernameTooLong { max: MAX_USERNAME_LEN, actual: username.len() });
+    }
+
+    let username_regex = Regex::new(r"^[a-zA-Z0-9_]+$")
+        .expect("username regex is a fixed, valid pattern");
+
+    if !username_regex.is_match(username) {
+        return Err(ValidationError::UsernameCharset);
+    }
+
+    Ok(())
+}
+
+/// Validates `username` and `email` together, aggregating every field
+/// failure into a single `UserError::ValidationError` instead of stopping at
+/// the first one.
+pub fn validate_user_fields(username: &str, email: &str) -> Result<(), UserError> {
+    let mut errors = Vec::new();
+
+    if let Err(e) = validate_username(username) {
+        errors.push(e);
+    }
+
+    if let Err(e) = validate_email(email) {
+        errors.push(e);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(UserError::ValidationError(ValidationErrors(errors)))
+    }
+}
+
+pub fn sanitize_string(input: &str) -> String {
+    input.trim().to_string()
+}